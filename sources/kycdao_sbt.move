@@ -42,6 +42,7 @@ module kycdao_sbt_obj::kycdao_sbt {
         token_uri_base: vector<u8>,
         subscription_cost_per_year: u64,
         price_feed_identifier: vector<u8>,
+        minting_paused: bool,
     }
 
     // This struct stores the challenge message that proves that the resource signer wants to mint this token
@@ -74,6 +75,8 @@ module kycdao_sbt_obj::kycdao_sbt {
     const EINVALID_PROOF_OF_KNOWLEDGE: u64 = 2;
     /// Specified address does not have a valid token
     const EINVALID_TOKEN: u64 = 3;
+    /// Minting has been paused by the admin
+    const EMINTING_PAUSED: u64 = 4;
 
     /// The ambassador token collection name
     const COLLECTION_NAME: vector<u8> = b"kycDAO SBT Collection";
@@ -131,6 +134,7 @@ module kycdao_sbt_obj::kycdao_sbt {
             token_uri_base: TOKEN_URI_BASE,
             subscription_cost_per_year: INITIAL_SUBSCRIPTION_COST,
             price_feed_identifier: PRICE_FEED_IDENTIFIER,
+            minting_paused: false,
         });
     }
 
@@ -145,6 +149,7 @@ module kycdao_sbt_obj::kycdao_sbt {
     /// `public_key_bytes` should be the public key of the admin
     public entry fun mint_with_signature(receiver: &signer, metadata_cid: String, expiry: u64, seconds_to_pay: u64, verification_tier: String, mint_proof_signature: vector<u8>) acquires ModuleData {
         let receiver_addr = signer::address_of(receiver);
+        assert!(!borrow_global<ModuleData>(@kycdao_sbt_obj).minting_paused, error::unavailable(EMINTING_PAUSED));
 
         if (seconds_to_pay > 0) {
             // calculate the mint cost in APT
@@ -248,6 +253,14 @@ module kycdao_sbt_obj::kycdao_sbt {
         module_data.subscription_cost_per_year = new_subscription_cost;
     }
 
+    /// Pause or resume minting, e.g. while a compromise of the mint key is investigated
+    public entry fun set_minting_paused(caller: &signer, paused: bool) acquires ModuleData {
+        let caller_address = signer::address_of(caller);
+        assert!(caller_address == @kycdao_admin, error::permission_denied(ENOT_AUTHORIZED));
+        let module_data = borrow_global_mut<ModuleData>(@kycdao_sbt_obj);
+        module_data.minting_paused = paused;
+    }
+
     public entry fun set_token_verified(caller: &signer, token_addr: address, new_verified: bool) acquires KycDAOToken {
         let caller_address = signer::address_of(caller);
         assert!(caller_address == @kycdao_admin, error::permission_denied(ENOT_AUTHORIZED));
@@ -296,6 +309,11 @@ module kycdao_sbt_obj::kycdao_sbt {
         token.verified && timestamp::now_seconds() < token.expiry
     }
 
+    #[view]
+    public fun is_minting_paused(): bool acquires ModuleData {
+        borrow_global<ModuleData>(@kycdao_sbt_obj).minting_paused
+    }
+
     #[view]
     public fun get_required_mint_cost_for_seconds(seconds: u64): u64 acquires ModuleData {
         let module_data = borrow_global<ModuleData>(@kycdao_sbt_obj);
@@ -319,6 +337,18 @@ module kycdao_sbt_obj::kycdao_sbt {
     // Tests
     //
 
+    #[test_only]
+    /// Set the on-chain time to `secs` seconds since the epoch, must be later than the current time
+    public fun set_chain_time(secs: u64) {
+        timestamp::update_global_time_for_test_secs(secs);
+    }
+
+    #[test_only]
+    /// Move the on-chain time forward by `secs` seconds
+    public fun advance_time(secs: u64) {
+        timestamp::fast_forward_seconds(secs);
+    }
+
     #[test_only]
     public fun set_up_test(
         origin_account: &signer,
@@ -329,7 +359,7 @@ module kycdao_sbt_obj::kycdao_sbt {
     ) acquires ModuleData {
         // set up global time for testing purpose
         timestamp::set_time_has_started_for_testing(aptos_framework);
-        timestamp::update_global_time_for_test_secs(10);
+        set_chain_time(10);
 
         create_account_for_test(signer::address_of(origin_account));
 
@@ -380,6 +410,41 @@ module kycdao_sbt_obj::kycdao_sbt {
 
     }
 
+    #[test (aptos_framework = @aptos_framework, origin_account = @0xcafe, resource_account = @0xc3bb8488ab1a5815a9d543d7e41b0e0df46a7396f89b22821f07a4362f75ddc5, nft_receiver = @0x123)]
+    public entry fun test_token_expiry(aptos_framework: signer, origin_account: signer, resource_account: signer, nft_receiver: signer) acquires ModuleData, KycDAOToken {
+        let (admin_sk, admin_pk) = ed25519::generate_keys();
+        set_up_test(&origin_account, &resource_account, &admin_pk, &nft_receiver, &aptos_framework);
+        let receiver_addr = signer::address_of(&nft_receiver);
+        let proof_challenge = MintProofChallenge {
+            receiver_account_sequence_number: account::get_sequence_number(receiver_addr),
+            receiver_account_address: receiver_addr,
+            metadata_cid: string::utf8(b"1234"), 
+            expiry: 1000, 
+            seconds_to_pay: 0, 
+            verification_tier: string::utf8(b"KYC_1")             
+        };
+
+        let sig = ed25519::sign_struct(&admin_sk, proof_challenge);
+
+        mint_with_signature(
+            &nft_receiver, 
+            string::utf8(b"1234"), 
+            1000, 
+            0, 
+            string::utf8(b"KYC_1"), 
+            ed25519::signature_to_bytes(&sig)
+        );
+        assert!(has_valid_token(receiver_addr), 1);
+
+        // still valid right before the expiry
+        set_chain_time(999);
+        assert!(has_valid_token(receiver_addr), 2);
+
+        // no longer valid once the expiry is reached
+        advance_time(1);
+        assert!(!has_valid_token(receiver_addr), 3);
+    }
+
     #[test (aptos_framework = @aptos_framework, origin_account = @0xcafe, resource_account = @0xc3bb8488ab1a5815a9d543d7e41b0e0df46a7396f89b22821f07a4362f75ddc5, nft_receiver = @0x123)]
     #[expected_failure(abort_code = 0x10002, location = kycdao_sbt_obj::kycdao_sbt)]
     public entry fun test_invalid_proof_struct(aptos_framework: signer, origin_account: signer, resource_account: signer, nft_receiver: signer) acquires ModuleData {
@@ -454,4 +519,85 @@ module kycdao_sbt_obj::kycdao_sbt {
         set_up_test(&origin_account, &resource_account, &admin_pk, &nft_receiver, &aptos_framework);
         set_subscription_cost(&origin_account, 0);
     }
+
+    #[test (aptos_framework = @aptos_framework, admin = @kycdao_admin, origin_account = @0xcafe, resource_account = @0xc3bb8488ab1a5815a9d543d7e41b0e0df46a7396f89b22821f07a4362f75ddc5, nft_receiver = @0x123)]
+    #[expected_failure(abort_code = 0xD0004, location = kycdao_sbt_obj::kycdao_sbt)]
+    public entry fun test_mint_when_paused(aptos_framework: signer, admin: signer, origin_account: signer, resource_account: signer, nft_receiver: signer) acquires ModuleData {
+        let (admin_sk, admin_pk) = ed25519::generate_keys();
+        set_up_test(&origin_account, &resource_account, &admin_pk, &nft_receiver, &aptos_framework);
+        let receiver_addr = signer::address_of(&nft_receiver);
+        let proof_challenge = MintProofChallenge {
+            receiver_account_sequence_number: account::get_sequence_number(receiver_addr),
+            receiver_account_address: receiver_addr,
+            metadata_cid: string::utf8(b"1234"), 
+            expiry: 100000000000, 
+            seconds_to_pay: 0, 
+            verification_tier: string::utf8(b"KYC_1")             
+        };
+
+        let sig = ed25519::sign_struct(&admin_sk, proof_challenge);
+
+        set_minting_paused(&admin, true);
+        assert!(is_minting_paused(), 1);
+
+        // a valid proof must still be rejected while minting is paused
+        mint_with_signature(
+            &nft_receiver, 
+            string::utf8(b"1234"), 
+            100000000000, 
+            0, 
+            string::utf8(b"KYC_1"), 
+            ed25519::signature_to_bytes(&sig)
+        );
+    }
+
+    #[test (aptos_framework = @aptos_framework, admin = @kycdao_admin, origin_account = @0xcafe, resource_account = @0xc3bb8488ab1a5815a9d543d7e41b0e0df46a7396f89b22821f07a4362f75ddc5, nft_receiver = @0x123)]
+    public entry fun test_mint_after_unpause(aptos_framework: signer, admin: signer, origin_account: signer, resource_account: signer, nft_receiver: signer) acquires ModuleData, KycDAOToken {
+        let (admin_sk, admin_pk) = ed25519::generate_keys();
+        set_up_test(&origin_account, &resource_account, &admin_pk, &nft_receiver, &aptos_framework);
+        let receiver_addr = signer::address_of(&nft_receiver);
+        let proof_challenge = MintProofChallenge {
+            receiver_account_sequence_number: account::get_sequence_number(receiver_addr),
+            receiver_account_address: receiver_addr,
+            metadata_cid: string::utf8(b"1234"), 
+            expiry: 100000000000, 
+            seconds_to_pay: 0, 
+            verification_tier: string::utf8(b"KYC_1")             
+        };
+
+        let sig = ed25519::sign_struct(&admin_sk, proof_challenge);
+
+        set_minting_paused(&admin, true);
+        set_minting_paused(&admin, false);
+        assert!(!is_minting_paused(), 1);
+
+        // minting works again once resumed
+        mint_with_signature(
+            &nft_receiver, 
+            string::utf8(b"1234"), 
+            100000000000, 
+            0, 
+            string::utf8(b"KYC_1"), 
+            ed25519::signature_to_bytes(&sig)
+        );
+        assert!(has_valid_token(receiver_addr), 2);
+    }
+
+    #[test (aptos_framework = @aptos_framework, origin_account = @0xcafe, resource_account = @0xc3bb8488ab1a5815a9d543d7e41b0e0df46a7396f89b22821f07a4362f75ddc5, nft_receiver = @0x123)]
+    #[expected_failure(abort_code = 0x50001, location = kycdao_sbt_obj::kycdao_sbt)]
+    public entry fun test_set_minting_paused_not_admin(aptos_framework: signer, origin_account: signer, resource_account: signer, nft_receiver: signer) acquires ModuleData {
+        let (_admin_sk, admin_pk) = ed25519::generate_keys();
+        set_up_test(&origin_account, &resource_account, &admin_pk, &nft_receiver, &aptos_framework);
+        set_minting_paused(&origin_account, true);
+    }
+
+    #[test (aptos_framework = @aptos_framework, origin_account = @0xcafe, resource_account = @0xc3bb8488ab1a5815a9d543d7e41b0e0df46a7396f89b22821f07a4362f75ddc5, nft_receiver = @0x123)]
+    public entry fun test_signer_cap_custody(aptos_framework: signer, origin_account: signer, resource_account: signer, nft_receiver: signer) acquires ModuleData {
+        let (_admin_sk, admin_pk) = ed25519::generate_keys();
+        set_up_test(&origin_account, &resource_account, &admin_pk, &nft_receiver, &aptos_framework);
+
+        // the signer capability retrieved during publishing must be held by the module and be for the resource account
+        let module_data = borrow_global<ModuleData>(@kycdao_sbt_obj);
+        assert!(account::get_signer_capability_address(&module_data.signer_cap) == signer::address_of(&resource_account), 1);
+    }
 }
\ No newline at end of file